@@ -24,16 +24,15 @@ async fn main() {
         .url("https://google.com")
         .data(&Data { foo: "bar" })
         .build();
-    let res = client
-        .send(&notification)
-        .await
-        .unwrap_or_else(|err: String| format!("Error: {}", err));
-    println!("Response is {res}");
+    match client.send(&notification).await {
+        Ok(response) => println!("Response is {}, idempotency key {}", response.body, response.idempotency_key),
+        Err(err) => println!("Error: {}", err),
+    }
 
     let res = client
         .create_or_update_user_attrs("hemanditwiz@gmail.com", &Data { foo: "bar" })
         .await
-        .unwrap_or_else(|err: String| format!("Error: {}", err));
+        .unwrap_or_else(|err| format!("Error: {}", err));
 
     println!("Response is {res}");
 }
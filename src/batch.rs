@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+use crate::NotificationItem;
+
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// One recipient's entry in a batch send, carrying that recipient's own merge data (name,
+/// order id, etc.) alongside the notification's shared content.
+#[derive(Serialize)]
+pub struct BatchRecipient<T: Serialize> {
+    pub recipient: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
+}
+
+/// Payload sent to Engagespot's bulk notification endpoint for a single chunk of recipients.
+#[derive(Serialize)]
+pub(crate) struct BatchNotification<'a, T: Serialize> {
+    pub notification: &'a NotificationItem,
+    pub recipients: &'a [BatchRecipient<T>],
+}
+
+/// Builder for a batch send to many recipients, each with their own merge data.
+///
+/// Use [`Self::recipient`] to add recipients one at a time, or [`Self::recipients`] to add
+/// many at once. [`crate::Engagespot::send_batch`] splits the recipient list into chunks of
+/// [`Self::batch_size`] (default 500) and issues one bulk request per chunk.
+pub struct BatchNotificationBuilder<T: Serialize> {
+    pub(crate) notification: NotificationItem,
+    pub(crate) recipients: Vec<BatchRecipient<T>>,
+    pub(crate) batch_size: usize,
+}
+
+impl<T: Serialize> BatchNotificationBuilder<T> {
+    /// Create a new batch notification builder with the title only.
+    /// Other fields can be set by chaining the methods.
+    /// **Example:**
+    /// ```
+    /// use engagespot::BatchNotificationBuilder;
+    /// let batch = BatchNotificationBuilder::<()>::new("Title")
+    ///     .recipient("foo@bar.com", None)
+    ///     .recipient("baz@bar.com", None);
+    /// ```
+    pub fn new(title: &str) -> Self {
+        BatchNotificationBuilder {
+            notification: NotificationItem::new(title),
+            recipients: Vec::new(),
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Set the notification item shared by every recipient in the batch.
+    pub fn notification_item(mut self, notification: NotificationItem) -> Self {
+        self.notification = notification;
+        self
+    }
+
+    /// Add a recipient with its own merge data to the batch.
+    pub fn recipient(mut self, recipient: &str, data: Option<T>) -> Self {
+        self.recipients.push(BatchRecipient {
+            recipient: recipient.to_string(),
+            data,
+        });
+        self
+    }
+
+    /// Add many `(recipient, data)` pairs to the batch at once.
+    pub fn recipients(mut self, recipients: Vec<(String, Option<T>)>) -> Self {
+        self.recipients
+            .extend(recipients.into_iter().map(|(recipient, data)| BatchRecipient { recipient, data }));
+        self
+    }
+
+    /// Set the maximum number of recipients sent per bulk request. Oversized recipient lists
+    /// are split into multiple requests. Default is 500.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_oversized_recipient_list_by_batch_size() {
+        let batch = BatchNotificationBuilder::<()>::new("Title")
+            .recipients(
+                (0..5)
+                    .map(|i| (format!("user{i}@example.com"), None))
+                    .collect(),
+            )
+            .batch_size(2);
+
+        let chunk_lens: Vec<usize> = batch
+            .recipients
+            .chunks(batch.batch_size)
+            .map(|chunk| chunk.len())
+            .collect();
+
+        assert_eq!(chunk_lens, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn chunks_exactly_fitting_recipient_list() {
+        let batch = BatchNotificationBuilder::<()>::new("Title")
+            .recipients((0..4).map(|i| (format!("user{i}@example.com"), None)).collect())
+            .batch_size(2);
+
+        let chunk_lens: Vec<usize> = batch
+            .recipients
+            .chunks(batch.batch_size)
+            .map(|chunk| chunk.len())
+            .collect();
+
+        assert_eq!(chunk_lens, vec![2, 2]);
+    }
+
+    #[test]
+    fn batch_size_is_clamped_to_at_least_one() {
+        let batch = BatchNotificationBuilder::<()>::new("Title").batch_size(0);
+        assert_eq!(batch.batch_size, 1);
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// A notification category. Notifications sent with a matching `category` are grouped and
+/// can have their own per-channel defaults configured in the Engagespot dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Category {
+    /// Unique identifier for the category.
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Icon shown for the category in the notification feed.
+    pub icon: Option<String>,
+}
+
+impl Category {
+    /// Create a new category with the given name.
+    pub fn new(name: &str) -> Self {
+        Category {
+            name: name.to_string(),
+            icon: None,
+        }
+    }
+
+    /// Set the icon of the category.
+    pub fn icon(mut self, icon: &str) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+/// A delivery channel supported by Engagespot.
+///
+/// Serializes to the camelCase channel key the Engagespot API expects (e.g. `WebPush` ->
+/// `"webPush"`), matching the `errorCode`-style casing used elsewhere in the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Channels {
+    Email,
+    Sms,
+    Push,
+    WebPush,
+    InApp,
+}
+
+/// Email-specific content that overrides the notification's common title/message when
+/// delivering over the email channel.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailOverride {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_body: Option<String>,
+}
+
+/// SMS-specific content that overrides the notification's common message when delivering
+/// over the SMS channel.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmsOverride {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+/// Web push-specific content that overrides the notification's common title/message when
+/// delivering over the web push channel.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPushOverride {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+}
+
+/// Channel-specific content override, keyed by [`Channels`] in [`crate::Notification`]'s
+/// `override` object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChannelOverride {
+    Email(EmailOverride),
+    Sms(SmsOverride),
+    WebPush(WebPushOverride),
+}
+
+impl From<EmailOverride> for ChannelOverride {
+    fn from(value: EmailOverride) -> Self {
+        ChannelOverride::Email(value)
+    }
+}
+
+impl From<SmsOverride> for ChannelOverride {
+    fn from(value: SmsOverride) -> Self {
+        ChannelOverride::Sms(value)
+    }
+}
+
+impl From<WebPushOverride> for ChannelOverride {
+    fn from(value: WebPushOverride) -> Self {
+        ChannelOverride::WebPush(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn channels_serialize_to_camel_case() {
+        assert_eq!(serde_json::to_string(&Channels::Email).unwrap(), "\"email\"");
+        assert_eq!(serde_json::to_string(&Channels::Sms).unwrap(), "\"sms\"");
+        assert_eq!(serde_json::to_string(&Channels::Push).unwrap(), "\"push\"");
+        assert_eq!(serde_json::to_string(&Channels::WebPush).unwrap(), "\"webPush\"");
+        assert_eq!(serde_json::to_string(&Channels::InApp).unwrap(), "\"inApp\"");
+    }
+
+    #[test]
+    fn email_override_serializes_camel_case_fields() {
+        let over = EmailOverride {
+            subject: Some("Custom subject".to_string()),
+            html_body: Some("<p>Hello</p>".to_string()),
+        };
+        let value: serde_json::Value = serde_json::from_str(&serde_json::to_string(&over).unwrap()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"subject": "Custom subject", "htmlBody": "<p>Hello</p>"})
+        );
+    }
+
+    #[test]
+    fn channel_overrides_map_keys_are_camel_case() {
+        let mut overrides: HashMap<Channels, ChannelOverride> = HashMap::new();
+        overrides.insert(
+            Channels::WebPush,
+            ChannelOverride::WebPush(WebPushOverride {
+                title: Some("Title".to_string()),
+                body: None,
+                icon: None,
+            }),
+        );
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&overrides).unwrap()).unwrap();
+        assert_eq!(value, serde_json::json!({"webPush": {"title": "Title"}}));
+    }
+}
@@ -1,7 +1,12 @@
 use reqwest::{header, Client, Error};
 use serde::Serialize;
+use std::time::Duration;
+use uuid::Uuid;
 
-use crate::Notification;
+use crate::batch::{BatchNotification, BatchNotificationBuilder};
+use crate::retry::RetryPolicy;
+use crate::workflow::WorkflowTrigger;
+use crate::{Category, EngagespotError, Notification};
 
 const DEFAULT_BASE_URL: &str = "https://api.engagespot.co/v3";
 
@@ -10,6 +15,7 @@ const DEFAULT_BASE_URL: &str = "https://api.engagespot.co/v3";
 pub struct EngagespotBuilder {
     base_url: String,
     client: Client,
+    retry_policy: RetryPolicy,
 }
 
 /// Engagespot client to communicate with Engagespot APIs for sending notifications, triggering templates,
@@ -19,6 +25,18 @@ pub struct Engagespot {
     base_url: String,
     /// The HTTP client used to make requests to Engagespot API. Default is reqwest::Client.
     client: Client,
+    /// Retry policy applied to [`Engagespot::send`]. No retries by default.
+    retry_policy: RetryPolicy,
+}
+
+/// Result of a successful [`Engagespot::send`] call.
+#[derive(Debug, Clone)]
+pub struct SendResponse {
+    /// Raw response body returned by the Engagespot API.
+    pub body: String,
+    /// Idempotency key generated for this logical notification. If the request was retried,
+    /// every attempt reused this key so Engagespot treats them as a single delivery.
+    pub idempotency_key: String,
 }
 
 fn create_default_client(api_key: &str, api_secret: &str) -> Result<Client, Error> {
@@ -62,6 +80,7 @@ impl EngagespotBuilder {
         EngagespotBuilder {
             base_url: DEFAULT_BASE_URL.to_string(),
             client,
+            retry_policy: RetryPolicy::none(),
         }
     }
 
@@ -72,11 +91,33 @@ impl EngagespotBuilder {
         self
     }
 
+    /// Sets the maximum number of times `send` retries a request that failed with a
+    /// transport error, `429` or `5xx` response. Default is 0 (no retries).
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.retry_policy.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the base delay used for `send`'s exponential backoff between retries. Default is
+    /// 200ms.
+    pub fn retry_base_delay(mut self, base_delay: Duration) -> Self {
+        self.retry_policy.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the whole retry policy at once, replacing any prior `max_retries`/`retry_base_delay`
+    /// calls. Useful when a [`RetryPolicy`] was built up elsewhere and handed to the builder.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Builds and returns the Engagespot client.
     pub fn build(self) -> Engagespot {
         Engagespot {
             base_url: self.base_url,
             client: self.client,
+            retry_policy: self.retry_policy,
         }
     }
 }
@@ -97,6 +138,10 @@ impl Engagespot {
     /// Sends a notification with required configuration
     /// send method takes a Notification struct as input.
     /// Notification struct can be created using the NotificationBuilder.
+    ///
+    /// Every call generates a fresh idempotency key and sends it as the `Idempotency-Key`
+    /// header; if [`EngagespotBuilder::max_retries`] is set, retries of the same logical send
+    /// reuse that key so Engagespot doesn't deliver the notification twice.
     /// **Example:**
     /// ```
     /// use engagespot::{Engagespot, NotificationBuilder};
@@ -110,12 +155,41 @@ impl Engagespot {
     pub async fn send<T: Serialize>(
         &self,
         notification: &Notification<T>,
-    ) -> Result<String, String> {
+    ) -> Result<SendResponse, EngagespotError> {
         let url = self.get_url("notifications");
-        let response = self.client.post(&url).json(&notification).send().await;
-        match response {
-            Ok(response) => self.handle_response(response).await,
-            Err(error) => Err(error.to_string()),
+        let idempotency_key = Uuid::new_v4().to_string();
+
+        let mut attempt = 0;
+        loop {
+            let result = self
+                .client
+                .post(&url)
+                .header("Idempotency-Key", &idempotency_key)
+                .json(&notification)
+                .send()
+                .await;
+
+            let outcome = match result {
+                Ok(response) => self.handle_response(response).await,
+                Err(error) => Err(EngagespotError::from(error)),
+            };
+
+            match outcome {
+                Ok(body) => {
+                    return Ok(SendResponse {
+                        body,
+                        idempotency_key,
+                    })
+                }
+                Err(error) if attempt < self.retry_policy.max_retries && error.is_retryable() => {
+                    let delay = error
+                        .retry_after()
+                        .unwrap_or_else(|| self.retry_policy.delay_for_attempt(attempt));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
         }
     }
 
@@ -138,20 +212,122 @@ impl Engagespot {
     /// }
     /// ```
     /// 
-    pub async fn create_or_update_user_attrs<T: Serialize>(&self, identifier: &str, attrs: &T) -> Result<String, String> {
+    pub async fn create_or_update_user_attrs<T: Serialize>(&self, identifier: &str, attrs: &T) -> Result<String, EngagespotError> {
         let url = self.get_url(format!("users/{identifier}").as_str());
-        let response = self.client.put(&url).json(&attrs).send().await;
-        match response {
-            Ok(response) => self.handle_response(response).await,
-            Err(error) => Err(error.to_string()),
+        let response = self.client.put(&url).json(&attrs).send().await?;
+        self.handle_response(response).await
+    }
+
+    /// Sends a batch notification to many recipients, each with their own merge data.
+    ///
+    /// The recipient list is split into chunks of [`BatchNotificationBuilder::batch_size`] and
+    /// sent as separate bulk requests; the returned `Vec` has one entry per chunk, in order,
+    /// so partial failures (e.g. the third chunk rate-limited) are visible without discarding
+    /// the chunks that succeeded.
+    /// **Example:**
+    /// ```
+    /// use engagespot::{BatchNotificationBuilder, Engagespot};
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///   let client = Engagespot::new("api_key", "api_secret");
+    ///   let batch = BatchNotificationBuilder::<()>::new("title")
+    ///       .recipient("hello@foo.com", None)
+    ///       .recipient("hey@foo.com", None);
+    ///   let results = client.send_batch(batch).await;
+    /// }
+    /// ```
+    pub async fn send_batch<T: Serialize>(
+        &self,
+        batch: BatchNotificationBuilder<T>,
+    ) -> Vec<Result<String, EngagespotError>> {
+        let url = self.get_url("notifications/bulk");
+        let mut results = Vec::new();
+        for chunk in batch.recipients.chunks(batch.batch_size) {
+            let payload = BatchNotification {
+                notification: &batch.notification,
+                recipients: chunk,
+            };
+            let result = self.client.post(&url).json(&payload).send().await;
+            let outcome = match result {
+                Ok(response) => self.handle_response(response).await,
+                Err(error) => Err(EngagespotError::from(error)),
+            };
+            results.push(outcome);
         }
+        results
+    }
+
+    /// Triggers a template/workflow run by its identifier, rendering `data` into whatever
+    /// template the workflow was configured with in the Engagespot dashboard.
+    /// **Example:**
+    /// ```
+    /// use serde::Serialize;
+    /// use engagespot::Engagespot;
+    /// #[derive(Serialize)]
+    /// struct Data {
+    ///     order_id: String,
+    /// }
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///   let client = Engagespot::new("api_key", "api_secret");
+    ///   let recipients = vec!["hello@foo.com".to_string()];
+    ///   let response = client
+    ///       .trigger_workflow("order-shipped", &recipients, &Data { order_id: "123".to_string() })
+    ///       .await;
+    /// }
+    /// ```
+    pub async fn trigger_workflow<T: Serialize>(
+        &self,
+        identifier: &str,
+        recipients: &Vec<String>,
+        data: &T,
+    ) -> Result<String, EngagespotError> {
+        let url = self.get_url("workflows/trigger");
+        let payload = WorkflowTrigger {
+            identifier,
+            recipients,
+            data,
+        };
+        let response = self.client.post(&url).json(&payload).send().await?;
+        self.handle_response(response).await
     }
 
-    async fn handle_response(&self, response: reqwest::Response) -> Result<String, String> {
+    /// Creates a new notification category.
+    pub async fn create_category(&self, category: &Category) -> Result<String, EngagespotError> {
+        let url = self.get_url("categories");
+        let response = self.client.post(&url).json(category).send().await?;
+        self.handle_response(response).await
+    }
+
+    /// Lists every notification category.
+    pub async fn list_categories(&self) -> Result<String, EngagespotError> {
+        let url = self.get_url("categories");
+        let response = self.client.get(&url).send().await?;
+        self.handle_response(response).await
+    }
+
+    /// Updates an existing notification category by name.
+    pub async fn update_category(&self, name: &str, category: &Category) -> Result<String, EngagespotError> {
+        let url = self.get_url(format!("categories/{name}").as_str());
+        let response = self.client.put(&url).json(category).send().await?;
+        self.handle_response(response).await
+    }
+
+    async fn handle_response(&self, response: reqwest::Response) -> Result<String, EngagespotError> {
         let status = response.status();
-        let response_text = response.text().await.unwrap();
+        let retry_after = response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let response_text = response.text().await?;
         if !status.is_success() {
-            return Err(response_text);
+            return Err(EngagespotError::from_response(
+                status.as_u16(),
+                retry_after,
+                &response_text,
+            ));
         }
         Ok(response_text)
     }
@@ -185,4 +361,22 @@ mod tests {
         let client = Engagespot::new("api_key", "api_secret");
         assert_eq!(client.base_url, "https://api.engagespot.co/v3");
     }
+
+    #[test]
+    fn builder_retry_policy() {
+        let client = EngagespotBuilder::new("api_key", "api_secret")
+            .max_retries(3)
+            .build();
+        assert_eq!(client.retry_policy.max_retries, 3);
+    }
+
+    #[test]
+    fn builder_retry_policy_setter() {
+        let policy = crate::RetryPolicy::new(5, std::time::Duration::from_millis(50));
+        let client = EngagespotBuilder::new("api_key", "api_secret")
+            .retry_policy(policy)
+            .build();
+        assert_eq!(client.retry_policy.max_retries, 5);
+        assert_eq!(client.retry_policy.base_delay, std::time::Duration::from_millis(50));
+    }
 }
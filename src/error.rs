@@ -0,0 +1,208 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Error body returned by the Engagespot API for non-2xx responses.
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    message: Option<String>,
+    #[serde(rename = "errorCode")]
+    error_code: Option<String>,
+}
+
+/// Errors that can occur while communicating with the Engagespot API.
+#[derive(Debug)]
+pub enum EngagespotError {
+    /// The request could not be sent, or the response could not be read (DNS, TLS, connection
+    /// reset, timeout, etc.).
+    Transport(reqwest::Error),
+    /// The API responded with a non-2xx status other than `401` or `429`. The body is parsed as
+    /// a structured error where possible; otherwise only `message` and `raw` carry its text.
+    Api {
+        /// HTTP status code returned by the API.
+        status: u16,
+        /// Machine readable error code from the `errorCode` field, if present.
+        code: Option<String>,
+        /// Human readable error message from the `message` field. Falls back to the raw body
+        /// when that field is missing or the body is not JSON, and to `"request failed"` when
+        /// the body is empty.
+        message: String,
+        /// Raw response body, kept for debugging and logging.
+        raw: String,
+    },
+    /// The API key/secret pair was rejected (`401 Unauthorized`).
+    Auth,
+    /// The API responded with `429 Too Many Requests`.
+    RateLimited {
+        /// Value of the `Retry-After` header, if the API sent one.
+        retry_after: Option<Duration>,
+    },
+}
+
+impl fmt::Display for EngagespotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EngagespotError::Transport(error) => write!(f, "{error}"),
+            EngagespotError::Api { message, .. } => write!(f, "{message}"),
+            EngagespotError::Auth => write!(f, "authentication failed, check your API key and secret"),
+            EngagespotError::RateLimited { retry_after } => match retry_after {
+                Some(duration) => write!(f, "rate limited, retry after {:?}", duration),
+                None => write!(f, "rate limited"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for EngagespotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EngagespotError::Transport(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for EngagespotError {
+    fn from(error: reqwest::Error) -> Self {
+        EngagespotError::Transport(error)
+    }
+}
+
+impl EngagespotError {
+    /// True if the request that produced this error is safe to retry: a transport error, a
+    /// `5xx` response, or `429 Too Many Requests`.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            EngagespotError::Transport(_) => true,
+            EngagespotError::RateLimited { .. } => true,
+            EngagespotError::Api { status, .. } => *status >= 500,
+            EngagespotError::Auth => false,
+        }
+    }
+
+    /// The `Retry-After` duration the API asked for, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            EngagespotError::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Build an [`EngagespotError`] from a non-2xx response's status, headers and body.
+    pub(crate) fn from_response(status: u16, retry_after: Option<Duration>, body: &str) -> Self {
+        if status == 401 {
+            return EngagespotError::Auth;
+        }
+        if status == 429 {
+            return EngagespotError::RateLimited { retry_after };
+        }
+
+        let fallback_message = || {
+            if body.is_empty() {
+                "request failed".to_string()
+            } else {
+                body.to_string()
+            }
+        };
+        let parsed: Option<ApiErrorBody> = serde_json::from_str(body).ok();
+        let (code, message) = match parsed {
+            Some(body) => (
+                body.error_code,
+                body.message.unwrap_or_else(fallback_message),
+            ),
+            None => (None, fallback_message()),
+        };
+
+        EngagespotError::Api {
+            status,
+            code,
+            message,
+            raw: body.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_response_401_is_auth() {
+        let error = EngagespotError::from_response(401, None, "");
+        assert!(matches!(error, EngagespotError::Auth));
+    }
+
+    #[test]
+    fn from_response_429_is_rate_limited_with_retry_after() {
+        let retry_after = Some(Duration::from_secs(30));
+        let error = EngagespotError::from_response(429, retry_after, "");
+        assert!(matches!(error, EngagespotError::RateLimited { retry_after: Some(d) } if d == Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn from_response_parses_json_error_body() {
+        let body = r#"{"message": "Invalid recipient", "errorCode": "INVALID_RECIPIENT"}"#;
+        let error = EngagespotError::from_response(422, None, body);
+        match error {
+            EngagespotError::Api {
+                status,
+                code,
+                message,
+                raw,
+            } => {
+                assert_eq!(status, 422);
+                assert_eq!(code, Some("INVALID_RECIPIENT".to_string()));
+                assert_eq!(message, "Invalid recipient");
+                assert_eq!(raw, body);
+            }
+            other => panic!("expected Api variant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_response_falls_back_to_raw_body_when_not_json() {
+        let error = EngagespotError::from_response(500, None, "Internal Server Error");
+        match error {
+            EngagespotError::Api {
+                status,
+                code,
+                message,
+                raw,
+            } => {
+                assert_eq!(status, 500);
+                assert_eq!(code, None);
+                assert_eq!(message, "Internal Server Error");
+                assert_eq!(raw, "Internal Server Error");
+            }
+            other => panic!("expected Api variant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_response_falls_back_to_generic_message_when_body_empty() {
+        let error = EngagespotError::from_response(503, None, "");
+        match error {
+            EngagespotError::Api { message, .. } => assert_eq!(message, "request failed"),
+            other => panic!("expected Api variant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn is_retryable_and_retry_after_by_variant() {
+        assert!(!EngagespotError::Auth.is_retryable());
+        assert_eq!(EngagespotError::Auth.retry_after(), None);
+
+        let rate_limited = EngagespotError::RateLimited {
+            retry_after: Some(Duration::from_secs(5)),
+        };
+        assert!(rate_limited.is_retryable());
+        assert_eq!(rate_limited.retry_after(), Some(Duration::from_secs(5)));
+
+        let server_error = EngagespotError::from_response(500, None, "");
+        assert!(server_error.is_retryable());
+
+        let client_error = EngagespotError::from_response(422, None, "");
+        assert!(!client_error.is_retryable());
+    }
+}
@@ -23,17 +23,28 @@
 //!   let client = Engagespot::new("api_key", "api_secret");
 //!
 //! let notification = NotificationBuilder::new("title", &vec!["foo@bar.com".to_string()]).build();
-//! let response = client.send(&notification).await.unwrap_or_else(|err: String| format!("Error: {}", err));
-//! println!("new res is {}", response);
+//! let response = client.send(&notification).await;
+//! println!("new res is {:?}", response.map(|r| r.body));
 //! }
 //! ```
 
+mod batch;
+mod category;
 mod channels;
 mod client;
+mod error;
+mod localized;
 mod notification;
 mod notification_item;
+mod retry;
+mod workflow;
 
-pub use channels::Channels;
-pub use client::{Engagespot, EngagespotBuilder};
+pub use batch::{BatchNotificationBuilder, BatchRecipient};
+pub use category::Category;
+pub use channels::{ChannelOverride, Channels, EmailOverride, SmsOverride, WebPushOverride};
+pub use client::{Engagespot, EngagespotBuilder, SendResponse};
+pub use error::EngagespotError;
+pub use localized::LocalizedText;
 pub use notification::{Notification, NotificationBuilder};
-pub use notification_item::NotificationItem;
+pub use notification_item::{NotificationItem, Priority};
+pub use retry::RetryPolicy;
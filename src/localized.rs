@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Notification text translated into multiple languages, keyed by language code
+/// (e.g. `"en"`, `"es"`).
+///
+/// Serializes as a plain JSON object mapping language code to text.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocalizedText(HashMap<String, String>);
+
+impl LocalizedText {
+    /// Create an empty `LocalizedText`. Languages can be added by chaining [`Self::with`].
+    pub fn new() -> Self {
+        LocalizedText(HashMap::new())
+    }
+
+    /// Add the text for a language and return the updated `LocalizedText`.
+    /// **Example:**
+    /// ```
+    /// use engagespot::LocalizedText;
+    /// let localized = LocalizedText::new()
+    ///     .with("en", "Hello")
+    ///     .with("es", "Hola");
+    /// ```
+    pub fn with(mut self, language: &str, text: &str) -> Self {
+        self.0.insert(language.to_string(), text.to_string());
+        self
+    }
+}
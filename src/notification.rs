@@ -1,4 +1,8 @@
-use crate::NotificationItem;
+use std::collections::HashMap;
+
+use crate::channels::ChannelOverride;
+use crate::notification_item::Priority;
+use crate::{Channels, LocalizedText, NotificationItem};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -15,6 +19,13 @@ pub struct Notification<T: Serialize = Option<String>> {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Category of the notification. If not provided, it will be sent to everyone.
     category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Channels to restrict delivery to. If not provided, Engagespot delivers through every
+    /// channel configured for the category.
+    channels: Option<Vec<Channels>>,
+    #[serde(rename = "override", skip_serializing_if = "Option::is_none")]
+    /// Channel-specific content that overrides the common title/message for that channel.
+    overrides: Option<HashMap<Channels, ChannelOverride>>,
 }
 
 pub struct NotificationBuilder<'a, T: Serialize> {
@@ -22,6 +33,8 @@ pub struct NotificationBuilder<'a, T: Serialize> {
     pub recipients: &'a Vec<String>,
     pub data: Option<T>,
     pub category: Option<String>,
+    pub channels: Option<Vec<Channels>>,
+    pub overrides: Option<HashMap<Channels, ChannelOverride>>,
 }
 
 impl<'a, T: Serialize> NotificationBuilder<'a, T> {
@@ -38,6 +51,8 @@ impl<'a, T: Serialize> NotificationBuilder<'a, T> {
             recipients,
             data: None,
             category: None,
+            channels: None,
+            overrides: None,
         }
     }
 
@@ -71,6 +86,30 @@ impl<'a, T: Serialize> NotificationBuilder<'a, T> {
         self
     }
 
+    /// Set the delivery priority of the notification item.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.notification = self.notification.priority(priority);
+        self
+    }
+
+    /// Set a per-language title, replacing the plain title in the outgoing JSON.
+    pub fn title_localized(mut self, localized: LocalizedText) -> Self {
+        self.notification = self.notification.title_localized(localized);
+        self
+    }
+
+    /// Set a per-language message, replacing the plain message in the outgoing JSON.
+    pub fn message_localized(mut self, localized: LocalizedText) -> Self {
+        self.notification = self.notification.message_localized(localized);
+        self
+    }
+
+    /// Set the fallback language for localized title/message content.
+    pub fn default_language(mut self, language: &str) -> Self {
+        self.notification = self.notification.default_language(language);
+        self
+    }
+
     /// Set the recipients of the notification.
     pub fn recipients(mut self, recipients: &'a Vec<String>) -> Self {
         self.recipients = recipients;
@@ -90,6 +129,32 @@ impl<'a, T: Serialize> NotificationBuilder<'a, T> {
         self
     }
 
+    /// Restrict delivery to the given channels. If not called, Engagespot delivers through
+    /// every channel configured for the category.
+    pub fn channels(mut self, channels: &[Channels]) -> Self {
+        self.channels = Some(channels.to_vec());
+        self
+    }
+
+    /// Override the content delivered over a specific channel, e.g. a distinct subject and
+    /// HTML body for email while other channels use the common title/message.
+    /// **Example:**
+    /// ```
+    /// use engagespot::{Channels, EmailOverride, NotificationBuilder};
+    /// let notification = NotificationBuilder::<()>::new("Title", &vec!["foo@bar.com".to_string()])
+    ///     .channel_override(Channels::Email, EmailOverride {
+    ///         subject: Some("Custom subject".to_string()),
+    ///         html_body: Some("<p>Hello</p>".to_string()),
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn channel_override(mut self, channel: Channels, override_: impl Into<ChannelOverride>) -> Self {
+        self.overrides
+            .get_or_insert_with(HashMap::new)
+            .insert(channel, override_.into());
+        self
+    }
+
     /// Build the notification. Returns the Notification struct.
     pub fn build(self) -> Notification<T> {
         Notification {
@@ -97,6 +162,8 @@ impl<'a, T: Serialize> NotificationBuilder<'a, T> {
             recipients: self.recipients.clone(),
             data: self.data,
             category: self.category,
+            channels: self.channels,
+            overrides: self.overrides,
         }
     }
 }
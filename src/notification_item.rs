@@ -1,15 +1,86 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::LocalizedText;
+
+/// Either a plain string or a per-language map. Serializes as whichever one is held, so a
+/// localized title/message takes the place of the plain string in the outgoing JSON.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+enum Text {
+    Plain(String),
+    Localized(LocalizedText),
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct NotificationItem {
     /// Title of the notification item. Required.
-    title: String,
+    title: Text,
     /// Message of the notification item.
-    message: Option<String>,
+    message: Option<Text>,
     /// Url of the notification item.
     url: Option<String>,
     /// Icon of the notification item.
     icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Delivery priority of the notification item.
+    priority: Option<Priority>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Fallback language to use when a recipient's language isn't present in a localized
+    /// title/message.
+    default_language: Option<String>,
+}
+
+/// Delivery priority of a notification.
+///
+/// Lets you control how urgently a notification should be delivered, independent of the
+/// channel's own defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    /// Deliver silently, without a badge or sound.
+    NoAlert,
+    /// Deliver without a sound.
+    Quiet,
+    /// Deliver using the channel's normal behavior. This is the default when no priority is set.
+    Normal,
+    /// Deliver even if the recipient has quiet hours configured.
+    High,
+    /// Deliver and mark the notification as requiring explicit acknowledgement from the recipient.
+    RequireConfirmation,
+}
+
+impl Serialize for Priority {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self {
+            Priority::NoAlert => -2,
+            Priority::Quiet => -1,
+            Priority::Normal => 0,
+            Priority::High => 1,
+            Priority::RequireConfirmation => 2,
+        };
+        serializer.serialize_i8(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = i8::deserialize(deserializer)?;
+        match value {
+            -2 => Ok(Priority::NoAlert),
+            -1 => Ok(Priority::Quiet),
+            0 => Ok(Priority::Normal),
+            1 => Ok(Priority::High),
+            2 => Ok(Priority::RequireConfirmation),
+            other => Err(serde::de::Error::custom(format!(
+                "invalid priority value: {other}"
+            ))),
+        }
+    }
 }
 
 impl NotificationItem {
@@ -25,10 +96,12 @@ impl NotificationItem {
     /// ```
     pub fn new(title: &str) -> Self {
         NotificationItem {
-            title: title.to_string(),
+            title: Text::Plain(title.to_string()),
             message: None,
             url: None,
             icon: None,
+            priority: None,
+            default_language: None,
         }
     }
 
@@ -40,22 +113,44 @@ impl NotificationItem {
     /// ```
     pub fn with_args(title: &str, message: &str, url: &str, icon: &str) -> Self {
         NotificationItem {
-            title: title.to_string(),
-            message: Some(message.to_string()),
+            title: Text::Plain(title.to_string()),
+            message: Some(Text::Plain(message.to_string())),
             url: Some(url.to_string()),
             icon: Some(icon.to_string()),
+            priority: None,
+            default_language: None,
         }
     }
 
     /// Set the title of the notification item.
     pub fn title(mut self, title: &str) -> Self {
-        self.title = title.to_string();
+        self.title = Text::Plain(title.to_string());
         self
     }
 
     /// Set the message of the notification item.
     pub fn message(mut self, message: &str) -> Self {
-        self.message = Some(message.to_string());
+        self.message = Some(Text::Plain(message.to_string()));
+        self
+    }
+
+    /// Set a per-language title, replacing the plain title in the outgoing JSON.
+    /// Use [`Self::default_language`] to pick the fallback for recipients whose language
+    /// isn't present in `localized`.
+    pub fn title_localized(mut self, localized: LocalizedText) -> Self {
+        self.title = Text::Localized(localized);
+        self
+    }
+
+    /// Set a per-language message, replacing the plain message in the outgoing JSON.
+    pub fn message_localized(mut self, localized: LocalizedText) -> Self {
+        self.message = Some(Text::Localized(localized));
+        self
+    }
+
+    /// Set the fallback language for localized title/message content.
+    pub fn default_language(mut self, language: &str) -> Self {
+        self.default_language = Some(language.to_string());
         self
     }
 
@@ -70,4 +165,43 @@ impl NotificationItem {
         self.icon = Some(icon.to_string());
         self
     }
+
+    /// Set the delivery priority of the notification item.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priority_round_trips_through_json_for_every_variant() {
+        let variants = [
+            Priority::NoAlert,
+            Priority::Quiet,
+            Priority::Normal,
+            Priority::High,
+            Priority::RequireConfirmation,
+        ];
+        for priority in variants {
+            let json = serde_json::to_string(&priority).unwrap();
+            let round_tripped: Priority = serde_json::from_str(&json).unwrap();
+            assert_eq!(round_tripped, priority);
+        }
+    }
+
+    #[test]
+    fn priority_serializes_to_expected_integers() {
+        assert_eq!(serde_json::to_string(&Priority::NoAlert).unwrap(), "-2");
+        assert_eq!(serde_json::to_string(&Priority::Quiet).unwrap(), "-1");
+        assert_eq!(serde_json::to_string(&Priority::Normal).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&Priority::High).unwrap(), "1");
+        assert_eq!(
+            serde_json::to_string(&Priority::RequireConfirmation).unwrap(),
+            "2"
+        );
+    }
 }
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Retry policy governing automatic retries of [`crate::Engagespot::send`] on transport
+/// errors, `429` and `5xx` responses.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_retries: u32,
+    pub(crate) base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// No automatic retries. This is the default.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            base_delay: Duration::from_millis(200),
+        }
+    }
+
+    /// Retry up to `max_retries` times, waiting `base_delay` doubled on each attempt (plus
+    /// jitter) before retrying.
+    pub fn new(max_retries: u32, base_delay: Duration) -> Self {
+        RetryPolicy {
+            max_retries,
+            base_delay,
+        }
+    }
+
+    /// Exponential backoff delay for the given retry attempt (0-indexed), with up to 50%
+    /// random jitter added to avoid many clients retrying in lockstep.
+    pub(crate) fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let exponential = self.base_delay.saturating_mul(1 << attempt.min(16));
+        let jitter_fraction: f64 = rand::thread_rng().gen_range(0.0..0.5);
+        exponential.mul_f64(1.0 + jitter_fraction)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::none()
+    }
+}
@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+/// Payload sent to trigger a template/workflow run.
+///
+/// Mirrors [`crate::Notification`]'s shape but targets a workflow `identifier` instead of
+/// carrying inline `NotificationItem` content, so the merge `data` is rendered into whatever
+/// template the workflow was configured with.
+#[derive(Serialize)]
+pub(crate) struct WorkflowTrigger<'a, T: Serialize> {
+    pub identifier: &'a str,
+    pub recipients: &'a Vec<String>,
+    pub data: &'a T,
+}